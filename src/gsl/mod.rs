@@ -0,0 +1,213 @@
+use std::marker::PhantomData;
+
+use ::bindings;
+use ::Real;
+use ::ffi::LandingPad;
+use ::traits::{IntegrandInput, IntegrandOutput};
+
+mod qawf;
+mod qaws;
+mod qawc;
+mod fixed;
+mod qagp;
+
+pub use self::qawf::{QAWF, ComplexIntegrationResult};
+pub use self::qaws::{QAWS, GSLIntegrationQAWSTable};
+pub use self::qawc::QAWC;
+pub use self::fixed::{Fixed, FixedType};
+pub use self::qagp::QAGP;
+
+/// Errors that can be returned by the GSL QUADPACK integrators. The variants
+/// below carry the `value`/`error` estimate GSL had accumulated when it gave
+/// up, where it still produces one, so callers can decide whether the
+/// partial result is usable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GSLIntegrationError {
+    /// The maximum number of subdivisions was reached before the requested
+    /// tolerance could be achieved (`GSL_EMAXITER`). Consider raising
+    /// `nintervals`.
+    MaxIterations { value: Real, error: Real },
+    /// Roundoff error was detected in the extrapolation table, so the
+    /// requested tolerance cannot be achieved (`GSL_EROUND`).
+    RoundoffError { value: Real, error: Real },
+    /// The integrand was too irregular for the adaptive subdivision to
+    /// handle, or evaluated to a non-finite value near a singular point
+    /// (`GSL_ESING`).
+    BadIntegrand { value: Real, error: Real },
+    /// The integral appears to be divergent, or converges too slowly to be
+    /// integrated numerically (`GSL_EDIVERGE`).
+    DivergentOrSlow { value: Real, error: Real },
+    /// The requested `epsabs`/`epsrel` tolerance cannot be achieved
+    /// (`GSL_EBADTOL`). Consider loosening the tolerances.
+    ToleranceUnattainable { value: Real, error: Real },
+    /// The Chebyshev moment table doesn't have enough levels for the
+    /// requested accuracy; raised by QAWO/QAWF (`GSL_ETABLE`).
+    TableTooSmall { value: Real, error: Real },
+    /// QAWC was asked to integrate with the `1/(x-c)` singularity `c` on one
+    /// of the interval endpoints, where GSL returns `GSL_EINVAL`.
+    SingularityOnBoundary,
+    /// QAGP was given fewer than two breakpoints, or breakpoints that
+    /// weren't in strictly ascending order (repeated points are rejected).
+    InvalidBreakpoints,
+    /// A `GSLIntegrationQAWSTable` was configured with `alpha`/`beta` not
+    /// greater than -1, or `mu`/`nu` outside `{0, 1}`.
+    InvalidWeightParameters,
+    /// A `Fixed` integrator's `alpha`/`beta` fell outside the domain its
+    /// `FixedType` weight family requires, so `gsl_integration_fixed_alloc`
+    /// returned NULL.
+    InvalidFixedParameters,
+    /// Catch-all for a GSL error code that hasn't been given its own variant.
+    GSLError(::GSLError),
+}
+
+/// Maps a nonzero QUADPACK return code to its `GSLIntegrationError` variant,
+/// attaching the `value`/`error` estimate GSL had accumulated so far.
+pub(crate) fn map_quadpack_error(retcode: i32, value: Real, error: Real) -> GSLIntegrationError {
+    match retcode {
+        bindings::GSL_EMAXITER => GSLIntegrationError::MaxIterations { value, error },
+        bindings::GSL_EROUND => GSLIntegrationError::RoundoffError { value, error },
+        bindings::GSL_ESING => GSLIntegrationError::BadIntegrand { value, error },
+        bindings::GSL_EDIVERGE => GSLIntegrationError::DivergentOrSlow { value, error },
+        bindings::GSL_EBADTOL => GSLIntegrationError::ToleranceUnattainable { value, error },
+        bindings::GSL_ETABLE => GSLIntegrationError::TableTooSmall { value, error },
+        _ => GSLIntegrationError::GSLError(retcode.into()),
+    }
+}
+
+/// An allocated `gsl_integration_workspace`, sized to hold a fixed number of
+/// subintervals. Freed automatically when dropped.
+#[derive(Debug)]
+pub(crate) struct GSLIntegrationWorkspace {
+    pub(crate) nintervals: usize,
+    pub(crate) wkspc: *mut bindings::gsl_integration_workspace,
+}
+
+impl GSLIntegrationWorkspace {
+    pub(crate) fn new(nintervals: usize) -> Self {
+        GSLIntegrationWorkspace {
+            nintervals,
+            wkspc: unsafe { bindings::gsl_integration_workspace_alloc(nintervals) },
+        }
+    }
+}
+
+impl Clone for GSLIntegrationWorkspace {
+    fn clone(&self) -> Self {
+        GSLIntegrationWorkspace::new(self.nintervals)
+    }
+}
+
+impl Drop for GSLIntegrationWorkspace {
+    fn drop(&mut self) {
+        unsafe { bindings::gsl_integration_workspace_free(self.wkspc) }
+    }
+}
+
+/// Selects which weight function a `GSLIntegrationQAWOTable` evaluates,
+/// mirroring `gsl_integration_qawo_enum`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GSLIntegrationQAWOEnum {
+    Sine,
+    Cosine,
+}
+
+impl GSLIntegrationQAWOEnum {
+    fn to_gsl(self) -> bindings::gsl_integration_qawo_enum {
+        match self {
+            GSLIntegrationQAWOEnum::Sine => bindings::gsl_integration_qawo_enum::GSL_INTEG_SINE,
+            GSLIntegrationQAWOEnum::Cosine => bindings::gsl_integration_qawo_enum::GSL_INTEG_COSINE,
+        }
+    }
+}
+
+/// An allocated `gsl_integration_qawo_table` describing the chirp/oscillation
+/// weight used by the QAWO and QAWF routines.
+#[derive(Debug)]
+pub(crate) struct GSLIntegrationQAWOTable {
+    nintervals: usize,
+    omega: Real,
+    length: Real,
+    kind: GSLIntegrationQAWOEnum,
+    pub(crate) table: *mut bindings::gsl_integration_qawo_table,
+}
+
+impl GSLIntegrationQAWOTable {
+    pub(crate) fn new(nintervals: usize, omega: Real, length: Real, kind: GSLIntegrationQAWOEnum) -> Self {
+        let table = unsafe {
+            bindings::gsl_integration_qawo_table_alloc(omega, length, kind.to_gsl(), nintervals)
+        };
+        GSLIntegrationQAWOTable { nintervals, omega, length, kind, table }
+    }
+
+    pub(crate) fn with_nintervals(self, nintervals: usize) -> Self {
+        GSLIntegrationQAWOTable::new(nintervals, self.omega, self.length, self.kind)
+    }
+
+    pub(crate) fn with_sin(mut self, omega: Real) -> Self {
+        self.set_weight(omega, GSLIntegrationQAWOEnum::Sine);
+        self
+    }
+
+    pub(crate) fn with_cos(mut self, omega: Real) -> Self {
+        self.set_weight(omega, GSLIntegrationQAWOEnum::Cosine);
+        self
+    }
+
+    /// Updates an already-allocated table in place via
+    /// `gsl_integration_qawo_table_set`, avoiding a fresh allocation.
+    pub(crate) fn set_weight(&mut self, omega: Real, kind: GSLIntegrationQAWOEnum) {
+        unsafe {
+            bindings::gsl_integration_qawo_table_set(self.table, omega, self.length, kind.to_gsl());
+        }
+        self.omega = omega;
+        self.kind = kind;
+    }
+}
+
+impl Clone for GSLIntegrationQAWOTable {
+    fn clone(&self) -> Self {
+        GSLIntegrationQAWOTable::new(self.nintervals, self.omega, self.length, self.kind)
+    }
+}
+
+impl Drop for GSLIntegrationQAWOTable {
+    fn drop(&mut self) {
+        unsafe { bindings::gsl_integration_qawo_table_free(self.table) }
+    }
+}
+
+/// A `gsl_function` bound to a Rust closure via a `LandingPad`, ready to be
+/// passed to a `gsl_integration_*` call.
+pub(crate) struct GSLFunction<'a, F: 'a> {
+    pub(crate) function: bindings::gsl_function,
+    _marker: PhantomData<&'a mut F>,
+}
+
+/// Wraps `fun` (via `lp`) as a `gsl_function` usable by the GSL integration
+/// routines. `lower_bound`/`upper_bound` are accepted but not used: they are
+/// neither stored in the returned `GSLFunction` nor checked.
+pub(crate) unsafe fn make_gsl_function<A, B, F>(lp: &mut LandingPad<F>,
+                                                 _lower_bound: Real,
+                                                 _upper_bound: Real)
+                                                 -> Result<GSLFunction<F>, GSLIntegrationError>
+    where A: IntegrandInput,
+          B: IntegrandOutput,
+          F: FnMut(A) -> B
+{
+    unsafe extern "C" fn trampoline<A, B, F>(x: f64, params: *mut ::std::os::raw::c_void) -> f64
+        where A: IntegrandInput,
+              B: IntegrandOutput,
+              F: FnMut(A) -> B
+    {
+        let lp = &mut *(params as *mut LandingPad<F>);
+        lp.call(x)
+    }
+
+    Ok(GSLFunction {
+        function: bindings::gsl_function {
+            function: Some(trampoline::<A, B, F>),
+            params: lp as *mut LandingPad<F> as *mut ::std::os::raw::c_void,
+        },
+        _marker: PhantomData,
+    })
+}
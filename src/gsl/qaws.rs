@@ -0,0 +1,198 @@
+use ::bindings;
+use ::{IntegrationResult, Integrator, Real};
+use ::ffi::LandingPad;
+use ::traits::{IntegrandInput, IntegrandOutput};
+
+use super::{make_gsl_function, GSLIntegrationError, GSLIntegrationWorkspace};
+
+/// An allocated `gsl_integration_qaws_table` describing the weight function
+/// `w(x) = (x-a)^alpha (b-x)^beta log^mu(x-a) log^nu(b-x)` used by QAWS.
+#[derive(Debug)]
+pub struct GSLIntegrationQAWSTable {
+    alpha: Real,
+    beta: Real,
+    mu: i32,
+    nu: i32,
+    pub(crate) table: *mut bindings::gsl_integration_qaws_table,
+}
+
+impl GSLIntegrationQAWSTable {
+    /// Allocates a table for the weight function with no singularities,
+    /// i.e. `alpha = beta = 0` and `mu = nu = 0`. Use `with_algebraic` and
+    /// `with_logarithmic` to configure the exponents.
+    pub fn new() -> Self {
+        GSLIntegrationQAWSTable::with_params(0.0, 0.0, 0, 0)
+            .expect("alpha = beta = 0, mu = nu = 0 is always a valid QAWS weight")
+    }
+
+    fn with_params(alpha: Real, beta: Real, mu: i32, nu: i32) -> Result<Self, GSLIntegrationError> {
+        let table = unsafe { bindings::gsl_integration_qaws_table_alloc(alpha, beta, mu, nu) };
+        if table.is_null() {
+            return Err(GSLIntegrationError::InvalidWeightParameters);
+        }
+        Ok(GSLIntegrationQAWSTable { alpha, beta, mu, nu, table })
+    }
+
+    /// Sets the algebraic singularity exponents `alpha` and `beta` in
+    /// `(x-a)^alpha (b-x)^beta`. Both must be greater than -1, otherwise
+    /// `GSLIntegrationError::InvalidWeightParameters` is returned.
+    pub fn with_algebraic(mut self, alpha: Real, beta: Real) -> Result<Self, GSLIntegrationError> {
+        if !(alpha > -1.0) || !(beta > -1.0) {
+            return Err(GSLIntegrationError::InvalidWeightParameters);
+        }
+        self.alpha = alpha;
+        self.beta = beta;
+        self.reset()?;
+        Ok(self)
+    }
+
+    /// Sets the logarithmic singularity powers `mu` and `nu` in
+    /// `log^mu(x-a) log^nu(b-x)`. Each must be 0 or 1, otherwise
+    /// `GSLIntegrationError::InvalidWeightParameters` is returned.
+    pub fn with_logarithmic(mut self, mu: i32, nu: i32) -> Result<Self, GSLIntegrationError> {
+        if (mu != 0 && mu != 1) || (nu != 0 && nu != 1) {
+            return Err(GSLIntegrationError::InvalidWeightParameters);
+        }
+        self.mu = mu;
+        self.nu = nu;
+        self.reset()?;
+        Ok(self)
+    }
+
+    fn reset(&mut self) -> Result<(), GSLIntegrationError> {
+        let retcode = unsafe {
+            bindings::gsl_integration_qaws_table_set(self.table, self.alpha, self.beta, self.mu, self.nu)
+        };
+        if retcode != bindings::GSL_SUCCESS {
+            Err(GSLIntegrationError::InvalidWeightParameters)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Clone for GSLIntegrationQAWSTable {
+    fn clone(&self) -> Self {
+        GSLIntegrationQAWSTable::with_params(self.alpha, self.beta, self.mu, self.nu)
+            .expect("parameters were already validated when this table was first configured")
+    }
+}
+
+impl Drop for GSLIntegrationQAWSTable {
+    fn drop(&mut self) {
+        unsafe { bindings::gsl_integration_qaws_table_free(self.table) }
+    }
+}
+
+/// Quadrature Adaptive Weighted integration for integrands with an
+/// algebraic-logarithmic singularity at one or both endpoints, i.e.
+/// `f(x) w(x)` where `w(x) = (x-a)^alpha (b-x)^beta log^mu(x-a) log^nu(b-x)`.
+/// Unlike QAWF and QAWO, QAWS integrates over a finite interval `(a, b)`.
+#[derive(Debug, Clone)]
+pub struct QAWS {
+    lower_bound: Real,
+    upper_bound: Real,
+    wkspc: GSLIntegrationWorkspace,
+    table: GSLIntegrationQAWSTable,
+}
+
+impl QAWS {
+    /// Creates a new QAWS with enough memory for `nintervals` subintervals,
+    /// integrating over (0, 1) with no singularities. See `with_bound`,
+    /// `with_algebraic` and `with_logarithmic` to configure the interval and
+    /// weight function.
+    pub fn new(nintervals: usize) -> Self {
+        QAWS {
+            lower_bound: 0.0,
+            upper_bound: 1.0,
+            wkspc: GSLIntegrationWorkspace::new(nintervals),
+            table: GSLIntegrationQAWSTable::new(),
+        }
+    }
+
+    /// Discards the old workspace and allocates a new one with enough
+    /// memory for `nintervals` subintervals.
+    pub fn with_nintervals(self, nintervals: usize) -> Self {
+        QAWS { wkspc: GSLIntegrationWorkspace::new(nintervals), ..self }
+    }
+
+    /// Updates the integration range to ( `lower_bound` , `upper_bound` ).
+    pub fn with_bound(self, lower_bound: Real, upper_bound: Real) -> Self {
+        QAWS { lower_bound, upper_bound, ..self }
+    }
+
+    /// Updates the algebraic part of the weight function, see
+    /// `GSLIntegrationQAWSTable::with_algebraic`.
+    pub fn with_algebraic(self, alpha: Real, beta: Real) -> Result<Self, GSLIntegrationError> {
+        Ok(QAWS { table: self.table.with_algebraic(alpha, beta)?, ..self })
+    }
+
+    /// Updates the logarithmic part of the weight function, see
+    /// `GSLIntegrationQAWSTable::with_logarithmic`.
+    pub fn with_logarithmic(self, mu: i32, nu: i32) -> Result<Self, GSLIntegrationError> {
+        Ok(QAWS { table: self.table.with_logarithmic(mu, nu)?, ..self })
+    }
+}
+
+impl Integrator for QAWS {
+    type Success = IntegrationResult;
+    type Failure = GSLIntegrationError;
+    fn integrate<A, B, F: FnMut(A) -> B>(&mut self, fun: F, epsrel: Real, epsabs: Real) -> Result<Self::Success, Self::Failure>
+        where A: IntegrandInput,
+              B: IntegrandOutput
+    {
+        let mut value: Real = 0.0;
+        let mut error: Real = 0.0;
+
+        let mut lp = LandingPad::new(fun);
+        let retcode = unsafe {
+            let mut gslfn = make_gsl_function(&mut lp, self.lower_bound, self.upper_bound)?;
+            bindings::gsl_integration_qaws(&mut gslfn.function,
+                                           self.lower_bound,
+                                           self.upper_bound,
+                                           self.table.table,
+                                           epsabs,
+                                           epsrel,
+                                           self.wkspc.nintervals,
+                                           self.wkspc.wkspc,
+                                           &mut value,
+                                           &mut error)
+        };
+        lp.maybe_resume_unwind();
+
+        if retcode != bindings::GSL_SUCCESS {
+            Err(super::map_quadpack_error(retcode, value, error))
+        } else {
+            Ok(IntegrationResult {
+                value, error
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_out_of_domain_algebraic_exponents() {
+        let err = GSLIntegrationQAWSTable::new().with_algebraic(-2.0, 0.0).unwrap_err();
+        assert_eq!(err, GSLIntegrationError::InvalidWeightParameters);
+    }
+
+    #[test]
+    fn rejects_out_of_domain_logarithmic_powers() {
+        let err = GSLIntegrationQAWSTable::new().with_logarithmic(2, 0).unwrap_err();
+        assert_eq!(err, GSLIntegrationError::InvalidWeightParameters);
+    }
+
+    #[test]
+    fn integrates_log_singularity_at_lower_bound() {
+        // ∫_0^1 log(x) dx = -1, via the weight log^1(x-0) log^0(1-x) with f(x) = 1.
+        let mut qaws = QAWS::new(1000)
+            .with_bound(0.0, 1.0)
+            .with_logarithmic(1, 0).unwrap();
+        let result = qaws.integrate(|_: Real| 1.0, 1e-10, 1e-10).unwrap();
+        assert!((result.value - -1.0).abs() < 1e-8);
+    }
+}